@@ -4,31 +4,181 @@
 
 // Import the generated bindings for getdata.
 use super::getdata_bindings::*;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::time::SystemTime;
+
+/// The set of errors reported by the underlying `getdata` library.
+///
+/// Each variant corresponds to a family of `GD_E_*` error codes returned by
+/// `gd_error()` and carries the human-readable message produced by
+/// `gd_error_string()`. Following the `io::Error`/`ErrorKind` split used in
+/// Rust's own `sys::unix::fs`, a raw C return is checked immediately after the
+/// FFI call and converted into one of these typed variants, so callers can
+/// distinguish an unknown field from a genuine I/O failure.
+#[derive(Debug)]
+pub enum GetDataError {
+    /// The dirfile could not be opened (`GD_E_OPEN`).
+    Open(String),
+    /// A field code did not name a field in the dirfile (`GD_E_BAD_CODE`).
+    BadCode(String),
+    /// A field's data type was not understood (`GD_E_BAD_TYPE`).
+    BadType(String),
+    /// A requested frame or sample range fell outside the data (`GD_E_RANGE`).
+    Range(String),
+    /// The underlying filesystem reported an I/O failure (`GD_E_IO`).
+    Io(String),
+    /// A path or field code contained an interior NUL byte and could not be
+    /// converted to a `CString`.
+    InvalidString(String),
+    /// Any other error code reported by getdata, carrying its message.
+    Other(String),
+}
+
+impl fmt::Display for GetDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GetDataError::Open(msg) => write!(f, "failed to open dirfile: {msg}"),
+            GetDataError::BadCode(msg) => write!(f, "bad field code: {msg}"),
+            GetDataError::BadType(msg) => write!(f, "bad field type: {msg}"),
+            GetDataError::Range(msg) => write!(f, "range error: {msg}"),
+            GetDataError::Io(msg) => write!(f, "I/O error: {msg}"),
+            GetDataError::InvalidString(msg) => write!(f, "invalid string: {msg}"),
+            GetDataError::Other(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GetDataError {}
+
+/// Convenience alias for results produced by this crate.
+pub type Result<T> = std::result::Result<T, GetDataError>;
+
+mod sealed {
+    /// Marker preventing `GdType` from being implemented outside this crate.
+    pub trait Sealed {}
+}
+
+/// Maps a Rust primitive onto the `gd_type_t_GD_*` constant describing it.
+///
+/// A value of type `T: GdType` can be read straight out of `gd_getdata` into a
+/// `Vec<T>`: the constant is passed to the FFI call so getdata fills the buffer
+/// in the caller's chosen representation, without the lossy `Vec<f64>` funnel.
+/// The trait is sealed — only the primitive types getdata understands may
+/// implement it.
+pub trait GdType: sealed::Sealed + Default + Clone {
+    /// The `gd_type_t` constant getdata uses to identify this Rust type.
+    const GD_TYPE: gd_type_t;
+}
+
+macro_rules! impl_gd_type {
+    ($($rust:ty => $gd:expr),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $rust {}
+            impl GdType for $rust {
+                const GD_TYPE: gd_type_t = $gd;
+            }
+        )*
+    };
+}
+
+impl_gd_type! {
+    u8 => gd_type_t_GD_UINT8,
+    i8 => gd_type_t_GD_INT8,
+    u16 => gd_type_t_GD_UINT16,
+    i16 => gd_type_t_GD_INT16,
+    u32 => gd_type_t_GD_UINT32,
+    i32 => gd_type_t_GD_INT32,
+    u64 => gd_type_t_GD_UINT64,
+    i64 => gd_type_t_GD_INT64,
+    f32 => gd_type_t_GD_FLOAT32,
+    f64 => gd_type_t_GD_FLOAT64,
+}
 
 /// Represents a wrapper for a DIRFILE, providing methods for interacting with Dirfile data.
+///
+/// The dirfile is closed automatically when the `Dirfile` is dropped. The raw
+/// handle is kept private so it cannot be copied out and closed twice.
 pub struct Dirfile {
     /// Pointer to the opened DIRFILE instance.
-    pub dirfile_open: *mut DIRFILE,
+    dirfile_open: *mut DIRFILE,
+}
+
+// A dirfile handle owns no thread-local state and may be moved between threads,
+// so `Dirfile` asserts `Send`. getdata performs no internal locking, however,
+// so concurrent access through a shared `&Dirfile` is unsound: we deliberately
+// do NOT implement `Sync`. Every read borrows `&self`, and with the handle
+// private and closed on `Drop`, the borrow checker prevents an outstanding read
+// from outliving the handle or from racing a close.
+unsafe impl Send for Dirfile {}
+
+impl Drop for Dirfile {
+    /// Closes the underlying dirfile, flushing and releasing its file handles.
+    ///
+    /// `gd_close` can fail to flush buffered writes on a writable dirfile, but
+    /// `Drop` cannot return that error, so a close failure here is silent. A
+    /// caller that needs to observe flush/close errors must call
+    /// [`sync`](Dirfile::sync) or [`flush`](Dirfile::flush) explicitly before
+    /// the handle is dropped.
+    fn drop(&mut self) {
+        if !self.dirfile_open.is_null() {
+            unsafe { gd_close(self.dirfile_open) };
+            self.dirfile_open = std::ptr::null_mut();
+        }
+    }
 }
 
 impl Dirfile {
-    /// Creates a new `Dirfile` instance by opening a specified path.
+    /// Creates a new `Dirfile` instance by opening a specified path read-only.
     ///
     /// # Arguments
     /// * `path` - A string slice that holds the path to the Dirfile.
     ///
     /// # Returns
-    /// A new `Dirfile` instance.
-    ///
-    /// # Panics
-    /// This function will panic if the path cannot be converted to a `CString`.
-    pub fn new(path: &str) -> Self {
-        let dirfile = CString::new(path).expect("CString::new failed");
-        let dirfile_ptr = dirfile.as_ptr();
-        let dirfile_open = unsafe { gd_open(dirfile_ptr, GD_RDONLY) };
-        Self {
-            dirfile_open,
+    /// A new `Dirfile` instance, or a [`GetDataError`] if the path is invalid
+    /// or `gd_open` could not open the dirfile.
+    pub fn new(path: &str) -> Result<Self> {
+        Self::open_with(path, GD_RDONLY)
+    }
+
+    /// Opens a dirfile with an explicit set of `gd_open` flags.
+    ///
+    /// Unlike [`new`](Self::new), which hard-codes `GD_RDONLY`, this accepts any
+    /// combination of getdata open flags — e.g. `GD_RDWR | GD_CREAT` — so the
+    /// dirfile can be opened for writing or created on the fly.
+    ///
+    /// # Arguments
+    /// * `path` - A string slice that holds the path to the Dirfile.
+    /// * `flags` - The `gd_open` flags, such as `GD_RDONLY` or `GD_RDWR | GD_CREAT`.
+    ///
+    /// # Returns
+    /// A new `Dirfile` instance, or a [`GetDataError`] if the path is invalid
+    /// or `gd_open` could not open the dirfile.
+    ///
+    /// # Writable dirfiles
+    /// When opened writable, buffered writes are flushed by `gd_close` on
+    /// [`Drop`], which cannot report an error. To observe flush/close failures,
+    /// call [`sync`](Self::sync) or [`flush`](Self::flush) before dropping the
+    /// handle.
+    pub fn open_with(path: &str, flags: u32) -> Result<Self> {
+        let dirfile = CString::new(path)
+            .map_err(|e| GetDataError::InvalidString(e.to_string()))?;
+        let dirfile_open = unsafe { gd_open(dirfile.as_ptr(), flags) };
+        // `gd_open` only returns null on allocation failure; refuse to build a
+        // `Dirfile` around it so no later method dereferences a null handle.
+        if dirfile_open.is_null() {
+            return Err(GetDataError::Open(format!(
+                "gd_open returned a null handle for {path}"
+            )));
+        }
+        let dirfile = Self { dirfile_open };
+        // An otherwise-valid handle may still carry an open-time error (missing
+        // format file, permission denied, ...); surface it rather than handing
+        // back a dirfile that every read will fail against.
+        if unsafe { gd_error(dirfile.dirfile_open) } == GD_E_OK as i32 {
+            Ok(dirfile)
+        } else {
+            Err(dirfile.last_error())
         }
     }
 
@@ -54,230 +204,551 @@ impl Dirfile {
     /// * `field` - The field name as a string slice.
     ///
     /// # Returns
-    /// The number of samples per frame as a `u32`.
-    ///
-    /// # Panics
-    /// This function will panic if the field name cannot be converted to a `CString`.
-    pub fn spf(&self, field: &str) -> u32 {
-        let field_code = CString::new(field).expect("CString::new failed");
-        let field_code_ptr = field_code.as_ptr();
-        unsafe { gd_spf(self.dirfile_open, field_code_ptr) }
+    /// The number of samples per frame as a `u32`, or a [`GetDataError`] if the
+    /// field code is invalid or unknown.
+    pub fn spf(&self, field: &str) -> Result<u32> {
+        let field_code = Self::field_cstring(field)?;
+        let spf = unsafe { gd_spf(self.dirfile_open, field_code.as_ptr()) };
+        // `gd_spf` returns zero on error; consult `gd_error` to find out why.
+        if spf == 0 {
+            return Err(self.last_error());
+        }
+        Ok(spf)
     }
 
-    /// Retrieves the type of a specified field in the Dirfile.
+    /// Retrieves the native type of a specified field in the Dirfile.
     ///
     /// # Arguments
     /// * `field` - The field name as a string slice.
     ///
     /// # Returns
-    /// The field type as a `u32`.
+    /// The field type as a `u32`, or a [`GetDataError`] if the field code is
+    /// invalid or unknown.
+    pub fn field_type(&self, field: &str) -> Result<u32> {
+        let field_code = Self::field_cstring(field)?;
+        let field_type = unsafe { gd_native_type(self.dirfile_open, field_code.as_ptr()) };
+        // `GD_UNKNOWN` (zero) is reported for a field code getdata cannot resolve.
+        if field_type == gd_type_t_GD_UNKNOWN {
+            return Err(self.last_error());
+        }
+        Ok(field_type)
+    }
+
+    /// Reads an entire field into a `Vec<T>` of the caller's chosen type.
+    ///
+    /// The `gd_type_t` constant for `T` is handed straight to `gd_getdata`, so
+    /// getdata materialises the samples in that representation with no
+    /// intermediate `f64` conversion — `get_field::<i64>` keeps the full 64-bit
+    /// integer range that [`get_data`](Self::get_data) would round off.
+    ///
+    /// # Arguments
+    /// * `field` - The field name as a string slice.
     ///
-    /// # Panics
-    /// This function will panic if the field name cannot be converted to a `CString`.
-    pub fn field_type(&self, field: &str) -> u32 {
-        let field_code = CString::new(field).expect("CString::new failed");
-        let field_code_ptr = field_code.as_ptr();
-        unsafe { gd_native_type(self.dirfile_open, field_code_ptr) }
+    /// # Returns
+    /// A vector of the samples read, truncated to the number `gd_getdata`
+    /// actually returned, or a [`GetDataError`] if the field cannot be read.
+    pub fn get_field<T: GdType>(&self, field: &str) -> Result<Vec<T>> {
+        let nframes = self.nframes();
+        let samples_per_frame = self.spf(field)?;
+        let total_samples = (nframes * samples_per_frame as i64) as usize;
+
+        let field_code = Self::field_cstring(field)?;
+        let mut raw_data = vec![T::default(); total_samples];
+        let read = unsafe {
+            gd_getdata(
+                self.dirfile_open,
+                field_code.as_ptr(),
+                0, 0,
+                nframes as usize,
+                samples_per_frame as usize,
+                T::GD_TYPE,
+                raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
+            )
+        };
+        self.check_error()?;
+        raw_data.truncate(read as usize);
+        Ok(raw_data)
     }
 
-    /// Retrieves the data for a specified field in the Dirfile and converts it to a `Vec<f64>`.
+    /// Retrieves the data for a specified field in the Dirfile as a `Vec<f64>`.
+    ///
+    /// This is a convenience wrapper over [`get_field::<f64>`](Self::get_field):
+    /// a general-purpose conversion that may not preserve the original precision
+    /// of wide integer types. Use `get_field` to read a field in its native
+    /// type, or [`get_strings`](Self::get_strings) for `GD_STRING` fields.
     ///
     /// # Arguments
     /// * `field` - The field name as a string slice.
     ///
     /// # Returns
-    /// A vector containing the data as `f64` values. This is a general-purpose conversion 
-    /// that may not preserve the original precision of some data types.
+    /// A vector containing the data as `f64` values, or a [`GetDataError`] if
+    /// the field cannot be read.
+    pub fn get_data(&self, field: &str) -> Result<Vec<f64>> {
+        self.get_field::<f64>(field)
+    }
+
+    /// Reads a `GD_STRING` field as a vector of owned [`String`]s.
     ///
-    /// # Panics
-    /// This function will panic if the field name cannot be converted to a `CString`.
-    pub fn get_data(&self, field: &str) -> Vec<f64> {
-        let field_type = self.field_type(field);
+    /// For string fields `gd_getdata` fills an array of `char *` pointing into
+    /// memory owned by the dirfile, so the bytes are copied out here rather than
+    /// parsed as numbers (which silently turned every non-numeric value into
+    /// `0.0` in the old `get_data`).
+    ///
+    /// # Arguments
+    /// * `field` - The field name as a string slice.
+    ///
+    /// # Returns
+    /// A vector of the strings read, or a [`GetDataError`] if the field cannot
+    /// be read.
+    pub fn get_strings(&self, field: &str) -> Result<Vec<String>> {
         let nframes = self.nframes();
-        let samples_per_frame = self.spf(field);
-        let total_samples = nframes * (samples_per_frame as i64);
-    
-        let field_code = CString::new(field).expect("CString::new failed");
-        let field_code_ptr = field_code.as_ptr();
-    
-        // Extract the data based on its type and convert to `Vec<f64>`.
-        let data: Vec<f64> = match field_type {
-            gd_type_t_GD_UINT8 => {
-                let mut raw_data = vec![0u8; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_UINT8,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_INT8 => {
-                let mut raw_data = vec![0i8; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_INT8,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_UINT16 => {
-                let mut raw_data = vec![0u16; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_UINT16,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_INT16 => {
-                let mut raw_data = vec![0i16; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_INT16,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_UINT32 => {
-                let mut raw_data = vec![0u32; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_UINT32,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_INT32 => {
-                let mut raw_data = vec![0i32; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_INT32,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_UINT64 => {
-                let mut raw_data = vec![0u64; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_UINT64,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_INT64 => {
-                let mut raw_data = vec![0i64; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_INT64,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_FLOAT32 => {
-                let mut raw_data = vec![0.0f32; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_FLOAT32,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.iter().map(|&v| v as f64).collect()
-            }
-            gd_type_t_GD_FLOAT64 => {
-                let mut raw_data = vec![0.0f64; total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_FLOAT64,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data
-            }
-            gd_type_t_GD_STRING => {
-                let mut raw_data = vec![CString::new("").unwrap(); total_samples as usize];
-                unsafe {
-                    gd_getdata(
-                        self.dirfile_open,
-                        field_code_ptr,
-                        0, 0,
-                        nframes as usize,
-                        samples_per_frame as usize,
-                        gd_type_t_GD_STRING,
-                        raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
-                    );
-                }
-                raw_data.into_iter()
-                    .filter_map(|c| c.into_string().ok())
-                    .map(|s| s.parse::<f64>().unwrap_or(0.0)) // Converts to f64 or defaults to 0.0.
-                    .collect()
+        let samples_per_frame = self.spf(field)?;
+        let total_samples = (nframes * samples_per_frame as i64) as usize;
+
+        let field_code = Self::field_cstring(field)?;
+        let mut raw_data: Vec<*const ::std::os::raw::c_char> =
+            vec![std::ptr::null(); total_samples];
+        let read = unsafe {
+            gd_getdata(
+                self.dirfile_open,
+                field_code.as_ptr(),
+                0, 0,
+                nframes as usize,
+                samples_per_frame as usize,
+                gd_type_t_GD_STRING,
+                raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
+            )
+        };
+        self.check_error()?;
+        raw_data.truncate(read as usize);
+        // For `GD_STRING`, `gd_getdata` writes borrowed pointers into a scratch
+        // buffer owned by the DIRFILE (valid until the dirfile is modified or
+        // closed); the caller must not free them. We copy each out into an owned
+        // `String` here, so nothing is leaked.
+        let strings = raw_data
+            .into_iter()
+            .filter(|ptr| !ptr.is_null())
+            .map(|ptr| unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+            .collect();
+        Ok(strings)
+    }
+
+    /// Reads a window of a field starting at an explicit frame offset.
+    ///
+    /// Unlike [`get_field`](Self::get_field), which allocates `nframes * spf`
+    /// samples and reads the whole field, this exposes the `first_frame` and
+    /// `num_frames` parameters `gd_getdata` already accepts so that a
+    /// multi-gigabyte field can be consumed one slice at a time.
+    ///
+    /// # Arguments
+    /// * `field` - The field name as a string slice.
+    /// * `first_frame` - The first frame to read, relative to the start of the field.
+    /// * `num_frames` - The number of frames to read.
+    ///
+    /// # Returns
+    /// A vector of the samples read, truncated to the number `gd_getdata`
+    /// actually returned, or a [`GetDataError`] if the field cannot be read.
+    pub fn get_range<T: GdType>(
+        &self,
+        field: &str,
+        first_frame: i64,
+        num_frames: usize,
+    ) -> Result<Vec<T>> {
+        let samples_per_frame = self.spf(field)? as usize;
+        let field_code = Self::field_cstring(field)?;
+        let mut raw_data = vec![T::default(); num_frames * samples_per_frame];
+        let read = unsafe {
+            gd_getdata(
+                self.dirfile_open,
+                field_code.as_ptr(),
+                first_frame, 0,
+                num_frames, 0,
+                T::GD_TYPE,
+                raw_data.as_mut_ptr() as *mut ::std::os::raw::c_void,
+            )
+        };
+        self.check_error()?;
+        raw_data.truncate(read as usize);
+        Ok(raw_data)
+    }
+
+    /// Returns an iterator that streams a field in fixed-size windows of frames.
+    ///
+    /// `window_frames` is the logical window the caller asks for; the iterator
+    /// keeps that distinct from the capacity of its single backing buffer — the
+    /// same split Rust's `HashMap` draws between a requested size and its
+    /// internal allocation — so refilling a window reuses the existing
+    /// allocation rather than growing a fresh one each step.
+    ///
+    /// # Arguments
+    /// * `field` - The field name as a string slice.
+    /// * `window_frames` - The number of frames yielded per window (must be non-zero).
+    pub fn field_chunks<T: GdType>(
+        &self,
+        field: &str,
+        window_frames: usize,
+    ) -> Result<FieldChunks<'_, T>> {
+        if window_frames == 0 {
+            return Err(GetDataError::Range(
+                "window_frames must be greater than zero".to_string(),
+            ));
+        }
+        let spf = self.spf(field)? as usize;
+        let total_frames = self.nframes().max(0) as usize;
+        let field_code = Self::field_cstring(field)?;
+        Ok(FieldChunks {
+            dirfile: self,
+            field_code,
+            spf,
+            next_frame: 0,
+            remaining_frames: total_frames,
+            window_frames,
+            buffer: Vec::with_capacity(window_frames * spf),
+        })
+    }
+
+    /// Returns the names of every field in the dirfile.
+    ///
+    /// # Returns
+    /// A vector of field codes (via `gd_field_list`), or a [`GetDataError`] if
+    /// the list could not be retrieved.
+    pub fn field_list(&self) -> Result<Vec<String>> {
+        let list = unsafe { gd_field_list(self.dirfile_open) };
+        self.check_error()?;
+        Ok(Self::collect_field_names(
+            list as *const *const ::std::os::raw::c_char,
+            self.nfields() as usize,
+        ))
+    }
+
+    /// Returns the names of every field of a given entry type.
+    ///
+    /// # Arguments
+    /// * `ty` - The entry type to filter by (e.g. `gd_entype_t_GD_RAW_ENTRY`).
+    ///
+    /// # Returns
+    /// A vector of matching field codes (via `gd_field_list_by_type`), or a
+    /// [`GetDataError`] if the list could not be retrieved.
+    pub fn field_list_by_type(&self, ty: gd_entype_t) -> Result<Vec<String>> {
+        let count = unsafe { gd_nfields_by_type(self.dirfile_open, ty) };
+        self.check_error()?;
+        let list = unsafe { gd_field_list_by_type(self.dirfile_open, ty) };
+        self.check_error()?;
+        Ok(Self::collect_field_names(
+            list as *const *const ::std::os::raw::c_char,
+            count as usize,
+        ))
+    }
+
+    /// Describes a single field: its entry type, native type, samples per frame,
+    /// and — for derived fields — the input fields it is computed from.
+    ///
+    /// # Arguments
+    /// * `field` - The field name as a string slice.
+    ///
+    /// # Returns
+    /// A [`FieldEntry`] populated from `gd_entry`, or a [`GetDataError`] if the
+    /// field code is unknown.
+    pub fn entry(&self, field: &str) -> Result<FieldEntry> {
+        let field_code = Self::field_cstring(field)?;
+        let mut raw = std::mem::MaybeUninit::<gd_entry_t>::zeroed();
+        let ret = unsafe { gd_entry(self.dirfile_open, field_code.as_ptr(), raw.as_mut_ptr()) };
+        if ret != 0 {
+            return Err(self.last_error());
+        }
+        let mut raw = unsafe { raw.assume_init() };
+        // The native type and spf come from the dedicated accessors so that
+        // derived fields (which compute their type) report correctly too.
+        let native_type = unsafe { gd_native_type(self.dirfile_open, field_code.as_ptr()) };
+        let spf = unsafe { gd_spf(self.dirfile_open, field_code.as_ptr()) };
+        let input_fields = raw
+            .in_fields
+            .iter()
+            .take_while(|ptr| !ptr.is_null())
+            .map(|&ptr| unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() })
+            .collect();
+        let entry = FieldEntry {
+            field: field.to_string(),
+            entry_type: raw.field_type,
+            native_type,
+            spf,
+            fragment_index: raw.fragment_index,
+            input_fields,
+        };
+        // `gd_entry` heap-allocates the strings and scalar arrays it writes into
+        // `raw` and hands ownership to us; release them now that everything has
+        // been copied into the owned `FieldEntry`.
+        unsafe { gd_free_entry(&mut raw) };
+        Ok(entry)
+    }
+
+    /// Reports the modification time of the most recently edited fragment
+    /// format file.
+    ///
+    /// Mirroring the per-file timestamps `MetadataExt` surfaces (`st_mtime` and
+    /// friends), this stats every fragment's format file (via `gd_fragmentname`)
+    /// and returns the newest `mtime`. Note this reflects *metadata* changes —
+    /// adding or editing field definitions — not data writes: appending samples
+    /// to a RAW field touches the binary data file, which this does not stat, so
+    /// this must not be used to detect newly appended frames.
+    ///
+    /// # Returns
+    /// The latest fragment format-file [`SystemTime`], or a [`GetDataError`] on
+    /// I/O failure.
+    pub fn mtime(&self) -> Result<SystemTime> {
+        let nfragments = unsafe { gd_nfragments(self.dirfile_open) };
+        self.check_error()?;
+        let mut latest: Option<SystemTime> = None;
+        for index in 0..nfragments {
+            let name_ptr = unsafe { gd_fragmentname(self.dirfile_open, index) };
+            self.check_error()?;
+            if name_ptr.is_null() {
+                continue;
             }
-            _ => {
-                println!("Unknown field type: {}", field_type);
-                Vec::new()
+            let path = unsafe { CStr::from_ptr(name_ptr) }.to_string_lossy().into_owned();
+            let modified = std::fs::metadata(&path)
+                .and_then(|meta| meta.modified())
+                .map_err(|e| GetDataError::Io(e.to_string()))?;
+            match latest {
+                Some(current) if modified <= current => {}
+                _ => latest = Some(modified),
             }
+        }
+        latest.ok_or_else(|| GetDataError::Io("dirfile has no fragments".to_string()))
+    }
+
+    /// Writes samples into a field, starting at a given frame.
+    ///
+    /// The whole slice is written as `data.len()` trailing samples from
+    /// `first_frame` (backed by `gd_putdata`), so partial frames are permitted.
+    /// Requires the dirfile to have been opened writable via
+    /// [`open_with`](Self::open_with).
+    ///
+    /// # Arguments
+    /// * `field` - The field name as a string slice.
+    /// * `first_frame` - The frame at which to begin writing.
+    /// * `data` - The samples to write, in the field's native type `T`.
+    ///
+    /// # Returns
+    /// The number of samples actually written, so a short write can be detected,
+    /// or a [`GetDataError`] if the write failed.
+    pub fn put_field<T: GdType>(
+        &mut self,
+        field: &str,
+        first_frame: i64,
+        data: &[T],
+    ) -> Result<usize> {
+        let field_code = Self::field_cstring(field)?;
+        let written = unsafe {
+            gd_putdata(
+                self.dirfile_open,
+                field_code.as_ptr(),
+                first_frame, 0,
+                0, data.len(),
+                T::GD_TYPE,
+                data.as_ptr() as *const ::std::os::raw::c_void,
+            )
+        };
+        self.check_error()?;
+        Ok(written as usize)
+    }
+
+    /// Adds a new raw field to the dirfile (via `gd_add_raw`).
+    ///
+    /// # Arguments
+    /// * `field` - The field code of the new field.
+    /// * `ty` - The native sample type, e.g. `gd_type_t_GD_FLOAT64`.
+    /// * `spf` - The number of samples per frame.
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a [`GetDataError`] if the field could not be added.
+    pub fn add_raw_field(&mut self, field: &str, ty: gd_type_t, spf: u32) -> Result<()> {
+        let field_code = Self::field_cstring(field)?;
+        let ret = unsafe { gd_add_raw(self.dirfile_open, field_code.as_ptr(), ty, spf, 0) };
+        if ret != 0 {
+            return Err(self.last_error());
+        }
+        Ok(())
+    }
+
+    /// Adds a derived field from a field-specification line (via `gd_add_spec`).
+    ///
+    /// The spec is a single line in dirfile format-file syntax, e.g.
+    /// `"out LINCOM in 2 0"`, letting callers add LINCOM/PHASE/… fields without
+    /// assembling a raw `gd_entry_t`.
+    ///
+    /// # Arguments
+    /// * `field_spec` - The format-file line describing the derived field.
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a [`GetDataError`] if the field could not be added.
+    pub fn add_derived_field(&mut self, field_spec: &str) -> Result<()> {
+        let spec = Self::field_cstring(field_spec)?;
+        let ret = unsafe { gd_add_spec(self.dirfile_open, spec.as_ptr(), 0) };
+        if ret != 0 {
+            return Err(self.last_error());
+        }
+        Ok(())
+    }
+
+    /// Flushes buffered writes for every field to disk (via `gd_flush`).
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a [`GetDataError`] on failure.
+    pub fn flush(&mut self) -> Result<()> {
+        let ret = unsafe { gd_flush(self.dirfile_open, std::ptr::null()) };
+        if ret != 0 {
+            return Err(self.last_error());
+        }
+        Ok(())
+    }
+
+    /// Flushes and syncs buffered writes to stable storage (via `gd_sync`).
+    ///
+    /// # Returns
+    /// `Ok(())` on success, or a [`GetDataError`] on failure.
+    pub fn sync(&mut self) -> Result<()> {
+        let ret = unsafe { gd_sync(self.dirfile_open, std::ptr::null()) };
+        if ret != 0 {
+            return Err(self.last_error());
+        }
+        Ok(())
+    }
+
+    /// Collects the first `count` C strings of an array into owned `String`s;
+    /// a NULL array pointer yields an empty vector.
+    fn collect_field_names(list: *const *const ::std::os::raw::c_char, count: usize) -> Vec<String> {
+        if list.is_null() {
+            return Vec::new();
+        }
+        (0..count)
+            .map(|i| unsafe {
+                let ptr = *list.add(i);
+                CStr::from_ptr(ptr).to_string_lossy().into_owned()
+            })
+            .collect()
+    }
+
+    /// Builds a NUL-terminated field code, mapping an interior NUL to a typed error.
+    fn field_cstring(field: &str) -> Result<CString> {
+        CString::new(field).map_err(|e| GetDataError::InvalidString(e.to_string()))
+    }
+
+    /// Returns `Ok(())` if the handle has no pending error, otherwise the error.
+    fn check_error(&self) -> Result<()> {
+        if unsafe { gd_error(self.dirfile_open) } == GD_E_OK as i32 {
+            Ok(())
+        } else {
+            Err(self.last_error())
+        }
+    }
+
+    /// Reads the last error recorded on the handle and maps its `GD_E_*` code
+    /// onto a [`GetDataError`] variant carrying the `gd_error_string` message.
+    fn last_error(&self) -> GetDataError {
+        let code = unsafe { gd_error(self.dirfile_open) };
+        let msg = self.error_string();
+        if code == GD_E_OPEN as i32 {
+            GetDataError::Open(msg)
+        } else if code == GD_E_BAD_CODE as i32 {
+            GetDataError::BadCode(msg)
+        } else if code == GD_E_BAD_TYPE as i32 {
+            GetDataError::BadType(msg)
+        } else if code == GD_E_RANGE as i32 {
+            GetDataError::Range(msg)
+        } else if code == GD_E_IO as i32 {
+            GetDataError::Io(msg)
+        } else {
+            GetDataError::Other(msg)
+        }
+    }
+
+    /// Retrieves the human-readable description of the handle's last error.
+    fn error_string(&self) -> String {
+        let mut buf = vec![0 as ::std::os::raw::c_char; 4096];
+        unsafe {
+            gd_error_string(self.dirfile_open, buf.as_mut_ptr(), buf.len());
+            CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned()
+        }
+    }
+}
+
+/// Describes a field's metadata as reported by `gd_entry`.
+///
+/// For raw fields `native_type` and `spf` describe the on-disk samples and
+/// `input_fields` is empty; for derived fields (LINCOM, PHASE, ...) the type is
+/// the computed output type and `input_fields` lists the fields it reads from.
+#[derive(Debug, Clone)]
+pub struct FieldEntry {
+    /// The field code this entry describes.
+    pub field: String,
+    /// The entry type, e.g. `gd_entype_t_GD_RAW_ENTRY` or `gd_entype_t_GD_LINCOM_ENTRY`.
+    pub entry_type: gd_entype_t,
+    /// The native (or computed) sample type, e.g. `gd_type_t_GD_FLOAT64`.
+    pub native_type: gd_type_t,
+    /// The number of samples per frame.
+    pub spf: u32,
+    /// The index of the format fragment that defines this field.
+    pub fragment_index: ::std::os::raw::c_int,
+    /// Input fields for a derived field; empty for raw fields.
+    pub input_fields: Vec<String>,
+}
+
+/// A streaming cursor over a field, yielding successive windows of frames.
+///
+/// Created by [`Dirfile::field_chunks`]. Because each window borrows the
+/// cursor's reused backing buffer, `FieldChunks` cannot implement
+/// `std::iter::Iterator` (whose `Item` may not borrow the iterator); call
+/// [`next_chunk`](Self::next_chunk) in a `while let` loop instead. The borrow
+/// also ties every window to `&self` on the [`Dirfile`], so a chunk cannot
+/// outlive the handle it was read from.
+pub struct FieldChunks<'a, T: GdType> {
+    dirfile: &'a Dirfile,
+    field_code: CString,
+    spf: usize,
+    next_frame: i64,
+    remaining_frames: usize,
+    window_frames: usize,
+    /// Backing buffer reused across iterations; its capacity is the internal
+    /// allocation, kept distinct from the `window_frames` the caller requested.
+    buffer: Vec<T>,
+}
+
+impl<T: GdType> FieldChunks<'_, T> {
+    /// Reads the next window of frames, or `None` once the field is exhausted.
+    ///
+    /// The returned slice borrows a buffer that is cleared and refilled in
+    /// place on every call, so streaming a whole field performs a single
+    /// allocation. A read error is reported once and ends the stream.
+    pub fn next_chunk(&mut self) -> Option<Result<&[T]>> {
+        if self.remaining_frames == 0 {
+            return None;
+        }
+        let frames = self.window_frames.min(self.remaining_frames);
+
+        // Clear-then-resize reuses the existing allocation whenever it is
+        // already large enough, rather than allocating a fresh window.
+        self.buffer.clear();
+        self.buffer.resize(frames * self.spf, T::default());
+        let read = unsafe {
+            gd_getdata(
+                self.dirfile.dirfile_open,
+                self.field_code.as_ptr(),
+                self.next_frame, 0,
+                frames, 0,
+                T::GD_TYPE,
+                self.buffer.as_mut_ptr() as *mut ::std::os::raw::c_void,
+            )
         };
-    
-        data // Return the processed data.
-    }    
+        if let Err(e) = self.dirfile.check_error() {
+            self.remaining_frames = 0;
+            return Some(Err(e));
+        }
+        self.buffer.truncate(read as usize);
+        self.next_frame += frames as i64;
+        self.remaining_frames -= frames;
+        Some(Ok(&self.buffer))
+    }
 }
 
 #[cfg(test)]
@@ -301,54 +772,144 @@ mod tests {
             panic!("Test Dirfile path does not exist: {}", TEST_DIRFILE_PATH);
         }
 
-        let dirfile = Dirfile::new(TEST_DIRFILE_PATH);
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
         assert!(!dirfile.dirfile_open.is_null(), "Failed to open Dirfile");
     }
 
     #[test]
     fn test_nfields() {
-        let dirfile = Dirfile::new(TEST_DIRFILE_PATH);
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
         let num_fields = dirfile.nfields();
         assert!(num_fields > 0, "Number of fields should be greater than zero");
     }
 
     #[test]
     fn test_nframes() {
-        let dirfile = Dirfile::new(TEST_DIRFILE_PATH);
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
         let num_frames = dirfile.nframes();
         assert!(num_frames > 0, "Number of frames should be greater than zero");
     }
 
     #[test]
     fn test_spf() {
-        let dirfile = Dirfile::new(TEST_DIRFILE_PATH);
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
         let field_name = TEST_FIELD_NAME;
-        let samples_per_frame = dirfile.spf(field_name);
+        let samples_per_frame = dirfile.spf(field_name).expect("Failed to read spf");
         assert!(samples_per_frame > 0, "Samples per frame should be greater than zero");
     }
 
     #[test]
     fn test_field_type() {
-        let dirfile = Dirfile::new(TEST_DIRFILE_PATH);
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
         let field_name = TEST_FIELD_NAME;
-        let field_type = dirfile.field_type(field_name);
+        let field_type = dirfile.field_type(field_name).expect("Failed to read field type");
         assert!(field_type > 0, "Field type should be greater than zero");
     }
 
     #[test]
     fn test_get_data() {
-        let dirfile = Dirfile::new(TEST_DIRFILE_PATH);
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
         let field_name = TEST_FIELD_NAME;
-        let data = dirfile.get_data(field_name);
+        let data = dirfile.get_data(field_name).expect("Failed to read data");
         assert!(!data.is_empty(), "Data should not be empty");
         assert!(data.iter().all(|&value| value.is_finite()), "All data values should be finite");
     }
 
+    #[test]
+    fn test_get_field_native_type() {
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
+        let field_name = TEST_FIELD_NAME;
+        let data = dirfile.get_field::<f64>(field_name).expect("Failed to read field");
+        assert!(!data.is_empty(), "Data should not be empty");
+        assert_eq!(
+            data,
+            dirfile.get_data(field_name).expect("Failed to read data"),
+            "get_data should agree with get_field::<f64>"
+        );
+    }
+
+    #[test]
+    fn test_get_range() {
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
+        let field_name = TEST_FIELD_NAME;
+        let window = dirfile.get_range::<f64>(field_name, 0, 1).expect("Failed to read range");
+        let spf = dirfile.spf(field_name).expect("Failed to read spf") as usize;
+        assert_eq!(window.len(), spf, "A one-frame window should hold spf samples");
+    }
+
+    #[test]
+    fn test_field_chunks_stream() {
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
+        let field_name = TEST_FIELD_NAME;
+        let mut chunks = dirfile
+            .field_chunks::<f64>(field_name, 8)
+            .expect("Failed to build chunk iterator");
+        let mut streamed = 0usize;
+        while let Some(chunk) = chunks.next_chunk() {
+            streamed += chunk.expect("Failed to read chunk").len();
+        }
+        let whole = dirfile.get_data(field_name).expect("Failed to read data");
+        assert_eq!(streamed, whole.len(), "Streaming should cover the whole field");
+    }
+
+    #[test]
+    fn test_field_list() {
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
+        let fields = dirfile.field_list().expect("Failed to list fields");
+        assert_eq!(
+            fields.len(),
+            dirfile.nfields() as usize,
+            "field_list should return one entry per field"
+        );
+        assert!(
+            fields.iter().any(|f| f == TEST_FIELD_NAME),
+            "field_list should contain the known test field"
+        );
+    }
+
+    #[test]
+    fn test_entry() {
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
+        let entry = dirfile.entry(TEST_FIELD_NAME).expect("Failed to read entry");
+        assert_eq!(entry.field, TEST_FIELD_NAME);
+        assert_eq!(
+            entry.spf,
+            dirfile.spf(TEST_FIELD_NAME).expect("Failed to read spf"),
+            "entry spf should match gd_spf"
+        );
+    }
+
+    #[test]
+    fn test_mtime() {
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
+        dirfile.mtime().expect("Failed to read mtime");
+    }
+
+    #[test]
+    fn test_write_roundtrip() {
+        // Create a fresh writable dirfile, add a raw field, write samples back.
+        let path = std::env::temp_dir().join("rustygetdata_write_test");
+        let path = path.to_str().expect("temp path is valid UTF-8");
+        let mut dirfile =
+            Dirfile::open_with(path, GD_RDWR | GD_CREAT | GD_TRUNC).expect("Failed to create Dirfile");
+        dirfile
+            .add_raw_field("written", gd_type_t_GD_FLOAT64, 1)
+            .expect("Failed to add raw field");
+        let samples = [1.0f64, 2.0, 3.0, 4.0];
+        let written = dirfile
+            .put_field("written", 0, &samples)
+            .expect("Failed to write field");
+        assert_eq!(written, samples.len(), "All samples should be written");
+        dirfile.flush().expect("Failed to flush");
+        let read_back = dirfile.get_field::<f64>("written").expect("Failed to read back");
+        assert_eq!(read_back, samples, "Read-back data should match what was written");
+    }
+
     #[test]
     fn test_get_data_unknown_field() {
-        let dirfile = Dirfile::new(TEST_DIRFILE_PATH);
+        let dirfile = Dirfile::new(TEST_DIRFILE_PATH).expect("Failed to open Dirfile");
         let unknown_field = FAKE_FIELD_NAME;
-        let data = dirfile.get_data(unknown_field);
-        assert!(data.is_empty(), "Data for an unknown field should be empty");
+        let result = dirfile.get_data(unknown_field);
+        assert!(result.is_err(), "Reading an unknown field should return an error");
     }
 }